@@ -1,7 +1,11 @@
 use serde::Deserialize;
 use regex::Regex;
+use pulldown_cmark::{html, Parser};
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fs;
 use std::error::Error;
+use std::path::Path;
 
 #[derive(Deserialize)]
 struct SiteMeta {
@@ -11,25 +15,85 @@ struct SiteMeta {
     logo: String,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Clone)]
 struct Issue {
     title: String,
     pdf: String,
     cover: String,
     description: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    date: Option<String>,
 }
 
 #[derive(Deserialize)]
 struct Metadata {
     site_meta: SiteMeta,
+    #[serde(default)]
     issues: Vec<Issue>,
 }
 
+// Front matter deserialized from a `content/*.md` file's `+++`-delimited TOML block.
+#[derive(Deserialize)]
+struct FrontMatter {
+    title: String,
+    pdf: String,
+    cover: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    date: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+// Loads issues from Zola-style `+++` front-matter Markdown files in `dir`, an
+// alternative to listing them all in metadata.json. Missing directory is not
+// an error -- it just means no issues are defined this way.
+fn load_content_issues(dir: &str) -> Result<Vec<Issue>, Box<dyn Error>> {
+    if !Path::new(dir).is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let front_matter_re = Regex::new(r"(?s)^\+\+\+\n(.*?)\n\+\+\+\n(.*)$").unwrap();
+    let mut issues = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("md") {
+            continue;
+        }
+
+        let raw = fs::read_to_string(&path)?;
+        let Some(caps) = front_matter_re.captures(&raw) else {
+            continue;
+        };
+
+        let front_matter: FrontMatter = toml::from_str(&caps[1])?;
+        let body = caps[2].trim();
+
+        issues.push(Issue {
+            title: front_matter.title,
+            pdf: front_matter.pdf,
+            cover: front_matter.cover,
+            description: front_matter.description.or_else(|| (!body.is_empty()).then(|| body.to_string())),
+            tags: front_matter.tags,
+            date: front_matter.date,
+        });
+    }
+
+    Ok(issues)
+}
+
 fn main() -> Result<(), Box<dyn Error>> {
     let data = fs::read_to_string("metadata.json")?;
     let meta: Metadata = serde_json::from_str(&data)?;
-    let sorted = sort_issues(meta.issues);
-    
+
+    let mut all_issues = meta.issues;
+    all_issues.extend(load_content_issues("content")?);
+    let sorted = sort_issues(all_issues);
+
     // Handle case when there are no issues
     let og_tags = if let Some(latest) = sorted.first() {
         format_og_tags(&meta.site_meta, latest)
@@ -37,7 +101,8 @@ fn main() -> Result<(), Box<dyn Error>> {
         format_default_og_tags(&meta.site_meta)
     };
     
-    let issue_cards = build_issue_cards(&sorted);
+    let slugs = generate_slugs(&sorted);
+    let issue_cards = build_issue_cards(&sorted, &slugs, "");
     let page_title = &meta.site_meta.site_name;
     let logo_html = if !meta.site_meta.logo.is_empty() {
         format!(r#"<img src="{}" alt="{} Logo">"#, meta.site_meta.logo, meta.site_meta.site_name)
@@ -53,37 +118,74 @@ fn main() -> Result<(), Box<dyn Error>> {
         .replace("{{LOGO}}", &logo_html);
 
     fs::write("index.html", final_html)?;
+
+    let feed_xml = build_feed(&meta.site_meta, &sorted);
+    fs::write("feed.xml", feed_xml)?;
+
+    let sitemap_xml = build_sitemap(&meta.site_meta, &sorted, &slugs);
+    fs::write("sitemap.xml", sitemap_xml)?;
+
+    let issue_template = fs::read_to_string("issue.template.html")?;
+    fs::create_dir_all("issues")?;
+    for (issue, slug) in sorted.iter().zip(slugs.iter()) {
+        let page_html = build_issue_page(&issue_template, &meta.site_meta, issue);
+        fs::write(format!("issues/{slug}.html"), page_html)?;
+    }
+
+    let tag_index = build_tag_index(&sorted, &slugs);
+    if !tag_index.is_empty() {
+        fs::create_dir_all("tags")?;
+        for (tag_slug, (tag_name, tag_issues)) in &tag_index {
+            let issues: Vec<Issue> = tag_issues.iter().map(|(issue, _)| (*issue).clone()).collect();
+            let issue_slugs: Vec<String> = tag_issues.iter().map(|(_, slug)| (*slug).clone()).collect();
+            let tag_cards = build_issue_cards(&issues, &issue_slugs, "../");
+            let tag_html = html_template
+                .replace("{{OG_TAGS}}", &format_default_og_tags(&meta.site_meta))
+                .replace("{{ISSUE_CARDS}}", &tag_cards)
+                .replace("{{PAGE_TITLE}}", &format!("{} | {}", escape_html(tag_name), escape_html(&meta.site_meta.site_name)))
+                .replace("{{LOGO}}", &logo_html);
+            fs::write(format!("tags/{tag_slug}.html"), tag_html)?;
+        }
+    }
+
     println!("✅ Successfully generated index.html with {} issues", sorted.len());
     Ok(())
 }
 
 fn sort_issues(mut issues: Vec<Issue>) -> Vec<Issue> {
-    let re = Regex::new(r"(\d{4})-(\d{2})-(\d{2})").unwrap();
-    
     issues.sort_by(|a, b| {
-        // Handle cases where regex might not match
-        let get_date_tuple = |filename: &str| -> (i32, u32, u32) {
-            if let Some(caps) = re.captures(filename) {
-                let year: i32 = caps[1].parse().unwrap_or(0);
-                let month: u32 = caps[2].parse().unwrap_or(1);
-                let day: u32 = caps[3].parse().unwrap_or(1);
-                (year, month, day)
-            } else {
-                (0, 1, 1) // Default for files without date pattern
-            }
-        };
-        
-        let (year_a, month_a, day_a) = get_date_tuple(&a.pdf);
-        let (year_b, month_b, day_b) = get_date_tuple(&b.pdf);
-        
+        let date_a = resolve_date(a).unwrap_or((0, 1, 1));
+        let date_b = resolve_date(b).unwrap_or((0, 1, 1));
+
         // Sort in descending order (newest first)
-        (year_b, month_b, day_b).cmp(&(year_a, month_a, day_a))
+        date_b.cmp(&date_a)
     });
-    
+
     issues
 }
 
-fn build_issue_cards(issues: &[Issue]) -> String {
+// Extracts a `YYYY-MM-DD` date out of a filename, e.g. from a pdf path.
+fn parse_date(filename: &str) -> Option<(i32, u32, u32)> {
+    let re = Regex::new(r"(\d{4})-(\d{2})-(\d{2})").unwrap();
+    let caps = re.captures(filename)?;
+
+    let year: i32 = caps[1].parse().ok()?;
+    let month: u32 = caps[2].parse().ok()?;
+    let day: u32 = caps[3].parse().ok()?;
+
+    Some((year, month, day))
+}
+
+// Resolves an issue's date, preferring its explicit `date` field (set via
+// content/ front matter) over the date parsed from its pdf filename.
+fn resolve_date(issue: &Issue) -> Option<(i32, u32, u32)> {
+    issue.date.as_deref().and_then(parse_date).or_else(|| parse_date(&issue.pdf))
+}
+
+// `prefix` is the relative path back to the site root from the page this
+// markup is rendered into: "" from index.html, "../" from tags/*.html, so
+// the same card HTML is navigable from either.
+fn build_issue_cards(issues: &[Issue], slugs: &[String], prefix: &str) -> String {
     if issues.is_empty() {
         return r#"<div class="empty-state">
     <h2>No Issues Available</h2>
@@ -91,35 +193,227 @@ fn build_issue_cards(issues: &[Issue]) -> String {
 </div>"#.to_string();
     }
 
-    issues.iter().map(|issue| {
-        let description = issue.description
-            .clone()
-            .unwrap_or_else(|| "Download this issue to read the full content.".to_string());
-        
+    issues.iter().zip(slugs.iter()).map(|(issue, slug)| {
+        let desc = match &issue.description {
+            Some(description) => render_markdown(description),
+            None => escape_html("Download this issue to read the full content."),
+        };
+
         format!(
             r#"<div class="issue-card">
     <div class="image-container">
-        <img src="{cover}" alt="{title}" loading="lazy">
+        <img src="{prefix}{cover}" alt="{title}" loading="lazy">
     </div>
     <div class="content">
-        <h3>{title}</h3>
-        <p>{desc}</p>
-        <a href="{pdf}" class="download-btn" download>Download PDF</a>
+        <h3><a href="{prefix}issues/{slug}.html">{title}</a></h3>
+        <div class="description">{desc}</div>
+        {tags}
+        <a href="{prefix}{pdf}" class="download-btn" download>Download PDF</a>
     </div>
 </div>"#,
+            prefix = prefix,
             cover = escape_html(&issue.cover),
             title = escape_html(&issue.title),
-            desc = escape_html(&description),
-            pdf = escape_html(&issue.pdf)
+            pdf = escape_html(&issue.pdf),
+            slug = slug,
+            tags = build_tag_chips(&issue.tags, prefix)
         )
     }).collect::<Vec<_>>().join("\n")
 }
 
+// Renders a description as sanitized HTML, allowing only the conservative set of tags in `ALLOWED_TAGS`.
+fn render_markdown(description: &str) -> String {
+    let parser = Parser::new(description);
+    let mut raw_html = String::new();
+    html::push_html(&mut raw_html, parser);
+    sanitize_html(&raw_html)
+}
+
+const ALLOWED_TAGS: [&str; 11] =
+    ["p", "br", "em", "strong", "i", "b", "a", "code", "ul", "ol", "li"];
+
+// Strips any tag not in `ALLOWED_TAGS` (keeping its text content) and drops all
+// attributes except a validated `href` on `<a>`, to keep rendered Markdown safe.
+fn sanitize_html(html: &str) -> String {
+    let tag_re = Regex::new(r"(?s)<(/?)([a-zA-Z][a-zA-Z0-9]*)([^>]*)>").unwrap();
+
+    tag_re.replace_all(html, |caps: &regex::Captures| {
+        let closing = &caps[1];
+        let name = caps[2].to_lowercase();
+
+        if !ALLOWED_TAGS.contains(&name.as_str()) {
+            return String::new();
+        }
+
+        if closing == "/" {
+            return format!("</{name}>");
+        }
+
+        if name == "a" {
+            return match extract_safe_href(&caps[3]) {
+                Some(href) => format!(r#"<a href="{href}">"#),
+                None => "<a>".to_string(),
+            };
+        }
+
+        format!("<{name}>")
+    }).to_string()
+}
+
+// Pulls an `href` attribute out of a raw attribute string, accepting only
+// http(s), root-relative, or fragment links (blocks `javascript:` etc.).
+fn extract_safe_href(attrs: &str) -> Option<String> {
+    let href_re = Regex::new(r#"href\s*=\s*"([^"]*)""#).unwrap();
+    let href = &href_re.captures(attrs)?[1];
+
+    if href.starts_with("http://") || href.starts_with("https://") || href.starts_with('/') || href.starts_with('#') {
+        Some(escape_html(href))
+    } else {
+        None
+    }
+}
+
+// Strips all HTML tags, leaving plain text suitable for a `<meta>` description.
+fn strip_html_tags(html: &str) -> String {
+    let tag_re = Regex::new(r"(?s)<[^>]*>").unwrap();
+    tag_re.replace_all(html, "").to_string()
+}
+
+// Renders clickable tag chips linking to each tag's index page. Empty/blank tags are skipped.
+fn build_tag_chips(tags: &[String], prefix: &str) -> String {
+    let chips = tags.iter()
+        .filter(|tag| !tag.trim().is_empty())
+        .map(|tag| format!(
+            r#"<a href="{prefix}tags/{slug}.html" class="tag-chip">{name}</a>"#,
+            prefix = prefix,
+            slug = slugify_tag(tag),
+            name = escape_html(tag)
+        ))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    if chips.is_empty() {
+        String::new()
+    } else {
+        format!(r#"<div class="tags">{chips}</div>"#)
+    }
+}
+
+// (display name, issues tagged with it)
+type TagEntry<'a> = (String, Vec<(&'a Issue, &'a String)>);
+
+// Groups issues by tag slug (so tags differing only by case/punctuation, e.g.
+// "Poetry" and "poetry", merge into a single page), skipping blank tags.
+// The display name kept is whichever spelling is encountered first.
+fn build_tag_index<'a>(issues: &'a [Issue], slugs: &'a [String]) -> HashMap<String, TagEntry<'a>> {
+    let mut index: HashMap<String, TagEntry<'a>> = HashMap::new();
+
+    for (issue, slug) in issues.iter().zip(slugs.iter()) {
+        let mut seen_tag_slugs = HashSet::new();
+        for tag in &issue.tags {
+            if tag.trim().is_empty() {
+                continue;
+            }
+            if !seen_tag_slugs.insert(slugify_tag(tag)) {
+                continue;
+            }
+            let entry = index.entry(slugify_tag(tag)).or_insert_with(|| (tag.clone(), Vec::new()));
+            entry.1.push((issue, slug));
+        }
+    }
+
+    index
+}
+
+fn slugify_tag(tag: &str) -> String {
+    let slug: String = tag
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect();
+    collapse_dashes(&slug)
+}
+
+fn build_issue_page(template: &str, site_meta: &SiteMeta, issue: &Issue) -> String {
+    let og_tags = format_og_tags(site_meta, issue);
+    let description = match &issue.description {
+        Some(description) => render_markdown(description),
+        None => escape_html(&site_meta.default_description),
+    };
+
+    template
+        .replace("{{OG_TAGS}}", &og_tags)
+        .replace("{{PAGE_TITLE}}", &format!("{} | {}", escape_html(&issue.title), escape_html(&site_meta.site_name)))
+        .replace("{{TITLE}}", &escape_html(&issue.title))
+        .replace("{{COVER}}", &escape_html(&issue.cover))
+        .replace("{{DESCRIPTION}}", &description)
+        .replace("{{PDF}}", &escape_html(&issue.pdf))
+}
+
+// Assigns each issue a unique URL slug, appending a numeric suffix on collision.
+// Checked against every slug generated so far (not just each base), so a
+// suffixed slug can never collide with another issue's unsuffixed slug.
+fn generate_slugs(issues: &[Issue]) -> Vec<String> {
+    let mut used: HashSet<String> = HashSet::new();
+
+    issues.iter().map(|issue| {
+        let base = slugify(issue);
+        let mut candidate = base.clone();
+        let mut suffix = 2;
+        while used.contains(&candidate) {
+            candidate = format!("{base}-{suffix}");
+            suffix += 1;
+        }
+        used.insert(candidate.clone());
+        candidate
+    }).collect()
+}
+
+// Builds a URL slug from an issue's title and its resolved date.
+fn slugify(issue: &Issue) -> String {
+    let date_prefix = resolve_date(issue).map(|(year, month, day)| format!("{year:04}-{month:02}-{day:02}"));
+
+    let title_slug: String = issue.title
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect();
+    let title_slug = collapse_dashes(&title_slug);
+
+    match date_prefix {
+        Some(date) if !title_slug.is_empty() => format!("{date}-{title_slug}"),
+        Some(date) => date,
+        None => title_slug,
+    }
+}
+
+fn collapse_dashes(slug: &str) -> String {
+    let mut result = String::new();
+    let mut last_was_dash = false;
+
+    for c in slug.chars() {
+        if c == '-' {
+            if !last_was_dash {
+                result.push('-');
+            }
+            last_was_dash = true;
+        } else {
+            result.push(c);
+            last_was_dash = false;
+        }
+    }
+
+    result.trim_matches('-').to_string()
+}
+
 fn format_og_tags(site_meta: &SiteMeta, issue: &Issue) -> String {
-    let desc = issue.description
-        .clone()
-        .unwrap_or_else(|| site_meta.default_description.clone());
-    
+    // Already HTML-escaped by `render_markdown`/`escape_html` below -- do not
+    // escape_html() this again or entities like "&amp;" become "&amp;amp;".
+    let desc = match &issue.description {
+        Some(description) => strip_html_tags(&render_markdown(description)),
+        None => escape_html(&site_meta.default_description),
+    };
+
     format!(
         r#"<meta property="og:title" content="{title} | {site}">
     <meta property="og:description" content="{desc}">
@@ -129,10 +423,11 @@ fn format_og_tags(site_meta: &SiteMeta, issue: &Issue) -> String {
     <meta property="og:locale" content="pa_IN">
     <meta name="twitter:card" content="summary_large_image">
     <meta name="twitter:image" content="{base}/{cover}">
-    <meta name="description" content="{desc}">"#,
+    <meta name="description" content="{desc}">
+    <link rel="alternate" type="application/rss+xml" title="{site} Feed" href="{base}/feed.xml">"#,
         title = escape_html(&issue.title),
         site = escape_html(&site_meta.site_name),
-        desc = escape_html(&desc),
+        desc = desc,
         base = site_meta.base_url.trim_end_matches('/'),
         cover = escape_html(&issue.cover)
     )
@@ -146,12 +441,123 @@ fn format_default_og_tags(site_meta: &SiteMeta) -> String {
     <meta property="og:type" content="website">
     <meta property="og:locale" content="pa_IN">
     <meta name="twitter:card" content="summary">
-    <meta name="description" content="{desc}">"#,
+    <meta name="description" content="{desc}">
+    <link rel="alternate" type="application/rss+xml" title="{site} Feed" href="{base}/feed.xml">"#,
         site = escape_html(&site_meta.site_name),
-        desc = escape_html(&site_meta.default_description)
+        desc = escape_html(&site_meta.default_description),
+        base = site_meta.base_url.trim_end_matches('/')
     )
 }
 
+// Splits any literal `]]>` so it can't terminate a `<![CDATA[...]]>` section early.
+fn cdata_escape(text: &str) -> String {
+    text.replace("]]>", "]]]]><![CDATA[>")
+}
+
+fn build_feed(site_meta: &SiteMeta, issues: &[Issue]) -> String {
+    let base = site_meta.base_url.trim_end_matches('/');
+
+    let items = issues.iter().map(|issue| {
+        let description = match &issue.description {
+            Some(description) => strip_html_tags(&render_markdown(description)),
+            None => site_meta.default_description.clone(),
+        };
+
+        format!(
+            r#"    <item>
+      <title>{title}</title>
+      <link>{base}/{pdf}</link>
+      <guid>{base}/{pdf}</guid>
+      <description><![CDATA[{desc}]]></description>
+      <enclosure url="{base}/{pdf}" type="application/pdf"/>
+      <pubDate>{pub_date}</pubDate>
+    </item>"#,
+            title = escape_html(&issue.title),
+            base = base,
+            pdf = escape_html(&issue.pdf),
+            desc = cdata_escape(&description),
+            pub_date = extract_pub_date(issue)
+        )
+    }).collect::<Vec<_>>().join("\n");
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0">
+  <channel>
+    <title>{title}</title>
+    <link>{base}</link>
+    <description>{desc}</description>
+    <language>pa</language>
+{items}
+  </channel>
+</rss>"#,
+        title = escape_html(&site_meta.site_name),
+        base = base,
+        desc = escape_html(&site_meta.default_description),
+        items = items
+    )
+}
+
+fn build_sitemap(site_meta: &SiteMeta, issues: &[Issue], slugs: &[String]) -> String {
+    let base = site_meta.base_url.trim_end_matches('/');
+
+    let homepage = format!(
+        r#"  <url>
+    <loc>{base}/</loc>
+  </url>"#
+    );
+
+    let issue_urls = issues.iter().zip(slugs.iter()).map(|(issue, slug)| {
+        let lastmod = resolve_date(issue)
+            .map(|(year, month, day)| format!("\n    <lastmod>{year:04}-{month:02}-{day:02}</lastmod>"))
+            .unwrap_or_default();
+
+        format!(
+            r#"  <url>
+    <loc>{base}/issues/{slug}.html</loc>{lastmod}
+  </url>"#
+        )
+    });
+
+    let urls = std::iter::once(homepage).chain(issue_urls).collect::<Vec<_>>().join("\n");
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+{urls}
+</urlset>"#
+    )
+}
+
+// Formats an issue's resolved date as RFC 822 for `<pubDate>`.
+fn extract_pub_date(issue: &Issue) -> String {
+    match resolve_date(issue) {
+        Some((year, month, day)) => format_rfc822(year, month, day),
+        None => String::new(),
+    }
+}
+
+fn format_rfc822(year: i32, month: u32, day: u32) -> String {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+    let weekday = WEEKDAYS[day_of_week(year, month, day)];
+    let month_name = MONTHS[(month.clamp(1, 12) - 1) as usize];
+
+    format!("{weekday}, {day:02} {month_name} {year} 00:00:00 GMT")
+}
+
+// Zeller's congruence, returns 0 = Sunday .. 6 = Saturday.
+fn day_of_week(year: i32, month: u32, day: u32) -> usize {
+    let (y, m) = if month < 3 { (year - 1, month + 12) } else { (year, month) };
+    let k = y % 100;
+    let j = y / 100;
+    let h = (day as i32 + (13 * (m as i32 + 1)) / 5 + k + k / 4 + j / 4 + 5 * j).rem_euclid(7);
+    ((h + 6) % 7) as usize
+}
+
 // Helper function to escape HTML characters
 fn escape_html(text: &str) -> String {
     text.replace('&', "&amp;")